@@ -5,22 +5,196 @@ use std::{fs::read_to_string, path::PathBuf};
 pub static CONFIG_FILENAMES: &[&str] = &[
     ".bureaucrat-config.yaml",
     ".bureaucrat-config.yml",
+    ".bureaucrat-config.toml",
     ".bureaucrat.yaml",
     ".bureaucrat.yml",
+    ".bureaucrat.toml",
 ];
 
+/// Default template, applied only once global and local config have been
+/// merged: two newlines, then the issue reference, then the existing
+/// message, preserving the hardcoded behavior this field replaces.
+const DEFAULT_TEMPLATE: &str = "\n\n{issue}{message}";
+
+const DEFAULT_TRAILER_KEY: &str = "Issue";
+
+/// How the issue reference is woven into the commit message.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Insertion {
+    /// Render `template` against the issue reference and commit message.
+    #[default]
+    Template,
+
+    /// Insert the reference as a Git trailer (e.g. `Issue: GH-123`) at the
+    /// bottom of the commit body.
+    Trailer,
+}
+
+/// A regex-based matcher for non-standard ticket formats: `pattern` is
+/// matched (anchored at the start of a branch segment) and `format` is
+/// rendered against the pattern's named captures to produce the
+/// reference. See `parse::find_pattern_reference`.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Pattern {
+    pub pattern: String,
+    pub format: String,
+}
+
+/// Forge hosting the referenced issues, used to pick an API shape and a
+/// default host in `forge::fetch_title`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ForgeKind {
+    GitHub,
+    GitLab,
+}
+
+#[cfg(feature = "forge")]
+impl ForgeKind {
+    pub fn default_host(&self) -> &'static str {
+        match self {
+            Self::GitHub => "api.github.com",
+            Self::GitLab => "gitlab.com",
+        }
+    }
+}
+
+fn default_forge_timeout_ms() -> u64 {
+    2000
+}
+
+/// Optional forge connection details used to enrich the rendered message
+/// with the referenced issue's title. See `forge::fetch_title`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Forge {
+    pub kind: ForgeKind,
+
+    /// Overrides the forge kind's default host, for GitHub/GitLab Enterprise
+    /// instances.
+    #[serde(default)]
+    pub host: Option<String>,
+
+    /// Repository path, e.g. `owner/name`.
+    pub repo: String,
+
+    /// Name of the environment variable holding the API token.
+    pub token_env: String,
+
+    #[serde(default = "default_forge_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Config {
     pub codes: Vec<String>,
 
     #[serde(default)]
     pub branch_prefixes: Vec<String>,
+
+    /// Regex patterns tried instead of the built-in code/CVE matching when
+    /// non-empty.
+    #[serde(default)]
+    pub patterns: Vec<Pattern>,
+
+    /// Template controlling how the issue reference is woven into the
+    /// commit message. See `template::render` for the placeholder syntax.
+    /// Only used when `insertion` is `Insertion::Template`. Left unset here
+    /// (rather than defaulted) so `merge` can tell a file that doesn't
+    /// mention it from one that explicitly wants the default; read it via
+    /// `Config::template`.
+    #[serde(default)]
+    pub template: Option<String>,
+
+    /// Selects between templated insertion and Git-trailer insertion. See
+    /// the note on `template` about why this is optional; read it via
+    /// `Config::insertion`.
+    #[serde(default)]
+    pub insertion: Option<Insertion>,
+
+    /// Trailer key used when `insertion` is `Insertion::Trailer`. See the
+    /// note on `template` about why this is optional; read it via
+    /// `Config::trailer_key`.
+    #[serde(default)]
+    pub trailer_key: Option<String>,
+
+    /// When set, enriches the rendered message with the referenced issue's
+    /// title fetched from this forge. See `forge::fetch_title`.
+    #[serde(default)]
+    pub forge: Option<Forge>,
 }
 
 impl Config {
     pub fn load(path: PathBuf) -> Result<Self, Error> {
-        let contents = read_to_string(path).map_err(Error::Io)?;
-        serde_yaml::from_str(&contents).map_err(Error::InvalidConfiguration)
+        let contents = read_to_string(&path).map_err(Error::Io)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => toml::from_str(&contents).map_err(Error::InvalidTomlConfiguration),
+            _ => serde_yaml::from_str(&contents).map_err(Error::InvalidConfiguration),
+        }
+    }
+
+    /// Template to render, falling back to the built-in default if neither
+    /// the local nor a merged global config set one.
+    pub fn template(&self) -> &str {
+        self.template.as_deref().unwrap_or(DEFAULT_TEMPLATE)
+    }
+
+    /// Insertion mode, falling back to `Insertion::Template` if neither the
+    /// local nor a merged global config set one.
+    pub fn insertion(&self) -> Insertion {
+        self.insertion.unwrap_or_default()
+    }
+
+    /// Trailer key, falling back to the built-in default if neither the
+    /// local nor a merged global config set one.
+    pub fn trailer_key(&self) -> &str {
+        self.trailer_key.as_deref().unwrap_or(DEFAULT_TRAILER_KEY)
+    }
+
+    /// Load and merge a global and a local configuration file, with the
+    /// local file taking precedence: its `codes` are unioned onto the
+    /// global ones, it overrides `branch_prefixes`/`patterns` (when
+    /// non-empty), and it overrides the remaining fields only where it
+    /// actually sets them, falling back to the global file's value (and
+    /// ultimately to the built-in defaults via `template`/`insertion`/
+    /// `trailer_key`) otherwise.
+    pub fn load_layered(paths: crate::git::ConfigPaths) -> Result<Self, Error> {
+        let global = paths.global.map(Self::load).transpose()?;
+        let local = paths.local.map(Self::load).transpose()?;
+        match (global, local) {
+            (Some(global), Some(local)) => Ok(global.merge(local)),
+            (Some(global), None) => Ok(global),
+            (None, Some(local)) => Ok(local),
+            (None, None) => Err(Error::NoConfigurationFile),
+        }
+    }
+
+    fn merge(self, local: Config) -> Config {
+        let mut codes = self.codes;
+        for code in local.codes {
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+        let branch_prefixes = if local.branch_prefixes.is_empty() {
+            self.branch_prefixes
+        } else {
+            local.branch_prefixes
+        };
+        let patterns = if local.patterns.is_empty() {
+            self.patterns
+        } else {
+            local.patterns
+        };
+        Config {
+            codes,
+            branch_prefixes,
+            patterns,
+            template: local.template.or(self.template),
+            insertion: local.insertion.or(self.insertion),
+            trailer_key: local.trailer_key.or(self.trailer_key),
+            forge: local.forge.or(self.forge),
+        }
     }
 }
 
@@ -51,7 +225,12 @@ branch_prefixes:
             Config::load(config_path).expect("Could not load test configuration"),
             Config {
                 codes: Vec::from([String::from("GH")]),
-                branch_prefixes: Vec::new()
+                branch_prefixes: Vec::new(),
+                patterns: Vec::new(),
+                template: None,
+                insertion: None,
+                trailer_key: None,
+                forge: None,
             }
         );
     }
@@ -67,11 +246,219 @@ branch_prefixes:
             Config::load(config_path).expect("Could not load test configuration"),
             Config {
                 codes: Vec::from([String::from("GH"), String::from("GIT")]),
-                branch_prefixes: Vec::from([String::from("feature"), String::from("release")])
+                branch_prefixes: Vec::from([String::from("feature"), String::from("release")]),
+                patterns: Vec::new(),
+                template: None,
+                insertion: None,
+                trailer_key: None,
+                forge: None,
             }
         );
     }
 
+    #[test]
+    fn load_config_template() {
+        let tmp_dir = TempDir::new().expect("Could not create temporary directory");
+        let config_path = tmp_dir.path().join("config.yaml");
+        let mut file = fs::File::create(&config_path).expect("Could not create configuration file");
+        file.write_all(b"codes:\n    - GH\ntemplate: \"{issue}: \"")
+            .expect("Could not write configuration file");
+        assert_eq!(
+            Config::load(config_path)
+                .expect("Could not load test configuration")
+                .template(),
+            "{issue}: "
+        );
+    }
+
+    #[test]
+    fn load_config_toml() {
+        let tmp_dir = TempDir::new().expect("Could not create temporary directory");
+        let config_path = tmp_dir.path().join(".bureaucrat.toml");
+        let mut file = fs::File::create(&config_path).expect("Could not create configuration file");
+        file.write_all(b"codes = [\"GH\", \"GIT\"]\nbranch_prefixes = [\"feature\"]")
+            .expect("Could not write configuration file");
+        let config = Config::load(config_path).expect("Could not load test configuration");
+        assert_eq!(
+            config.codes,
+            Vec::from([String::from("GH"), String::from("GIT")])
+        );
+        assert_eq!(config.branch_prefixes, Vec::from([String::from("feature")]));
+    }
+
+    #[test]
+    fn load_config_toml_invalid() {
+        let tmp_dir = TempDir::new().expect("Could not create temporary directory");
+        let config_path = tmp_dir.path().join(".bureaucrat.toml");
+        let mut file = fs::File::create(&config_path).expect("Could not create configuration file");
+        file.write_all(b"not = valid = toml")
+            .expect("Could not write configuration file");
+        assert!(matches!(
+            Config::load(config_path),
+            Err(Error::InvalidTomlConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn load_config_patterns() {
+        let tmp_dir = TempDir::new().expect("Could not create temporary directory");
+        let config_path = tmp_dir.path().join("config.yaml");
+        let mut file = fs::File::create(&config_path).expect("Could not create configuration file");
+        file.write_all(
+            b"codes:\n    - GH\npatterns:\n    - pattern: \"(?P<code>[A-Z]+)-?(?P<num>\\\\d+)\"\n      format: \"{code}-{num}\"",
+        )
+        .expect("Could not write configuration file");
+        let config = Config::load(config_path).expect("Could not load test configuration");
+        assert_eq!(
+            config.patterns,
+            Vec::from([Pattern {
+                pattern: String::from("(?P<code>[A-Z]+)-?(?P<num>\\d+)"),
+                format: String::from("{code}-{num}"),
+            }])
+        );
+    }
+
+    #[test]
+    fn load_config_forge() {
+        let tmp_dir = TempDir::new().expect("Could not create temporary directory");
+        let config_path = tmp_dir.path().join("config.yaml");
+        let mut file = fs::File::create(&config_path).expect("Could not create configuration file");
+        file.write_all(
+            b"codes:\n    - GH\nforge:\n    kind: github\n    repo: arttuperala/bureaucrat\n    token_env: GITHUB_TOKEN",
+        )
+        .expect("Could not write configuration file");
+        let config = Config::load(config_path).expect("Could not load test configuration");
+        assert_eq!(
+            config.forge,
+            Some(Forge {
+                kind: ForgeKind::GitHub,
+                host: None,
+                repo: String::from("arttuperala/bureaucrat"),
+                token_env: String::from("GITHUB_TOKEN"),
+                timeout_ms: default_forge_timeout_ms(),
+            })
+        );
+    }
+
+    #[test]
+    fn load_config_trailer_insertion() {
+        let tmp_dir = TempDir::new().expect("Could not create temporary directory");
+        let config_path = tmp_dir.path().join("config.yaml");
+        let mut file = fs::File::create(&config_path).expect("Could not create configuration file");
+        file.write_all(b"codes:\n    - GH\ninsertion: trailer\ntrailer_key: Refs")
+            .expect("Could not write configuration file");
+        let config = Config::load(config_path).expect("Could not load test configuration");
+        assert_eq!(config.insertion(), Insertion::Trailer);
+        assert_eq!(config.trailer_key(), "Refs");
+    }
+
+    #[test]
+    fn load_layered_merges_codes_and_overrides_branch_prefixes() {
+        let tmp_dir = TempDir::new().expect("Could not create temporary directory");
+        let global_path = tmp_dir.path().join("global.yaml");
+        fs::File::create(&global_path)
+            .expect("Could not create global configuration file")
+            .write_all(b"codes:\n    - GH\n    - JIRA\nbranch_prefixes:\n    - feature")
+            .expect("Could not write global configuration file");
+        let local_path = tmp_dir.path().join("local.yaml");
+        fs::File::create(&local_path)
+            .expect("Could not create local configuration file")
+            .write_all(b"codes:\n    - GH\n    - GIT")
+            .expect("Could not write local configuration file");
+
+        let config = Config::load_layered(crate::git::ConfigPaths {
+            global: Some(global_path),
+            local: Some(local_path),
+        })
+        .expect("Could not load layered configuration");
+
+        assert_eq!(
+            config.codes,
+            Vec::from([
+                String::from("GH"),
+                String::from("JIRA"),
+                String::from("GIT")
+            ])
+        );
+        assert_eq!(config.branch_prefixes, Vec::from([String::from("feature")]));
+    }
+
+    #[test]
+    fn load_layered_preserves_global_insertion_when_local_is_silent() {
+        let tmp_dir = TempDir::new().expect("Could not create temporary directory");
+        let global_path = tmp_dir.path().join("global.yaml");
+        fs::File::create(&global_path)
+            .expect("Could not create global configuration file")
+            .write_all(b"codes:\n    - GH\ninsertion: trailer\ntrailer_key: Refs\ntemplate: \"{issue}\"")
+            .expect("Could not write global configuration file");
+        let local_path = tmp_dir.path().join("local.yaml");
+        fs::File::create(&local_path)
+            .expect("Could not create local configuration file")
+            .write_all(b"codes:\n    - GH")
+            .expect("Could not write local configuration file");
+
+        let config = Config::load_layered(crate::git::ConfigPaths {
+            global: Some(global_path),
+            local: Some(local_path),
+        })
+        .expect("Could not load layered configuration");
+
+        assert_eq!(config.insertion(), Insertion::Trailer);
+        assert_eq!(config.trailer_key(), "Refs");
+        assert_eq!(config.template(), "{issue}");
+    }
+
+    #[test]
+    fn load_layered_local_overrides_global_insertion() {
+        let tmp_dir = TempDir::new().expect("Could not create temporary directory");
+        let global_path = tmp_dir.path().join("global.yaml");
+        fs::File::create(&global_path)
+            .expect("Could not create global configuration file")
+            .write_all(b"codes:\n    - GH\ninsertion: trailer\ntrailer_key: Refs")
+            .expect("Could not write global configuration file");
+        let local_path = tmp_dir.path().join("local.yaml");
+        fs::File::create(&local_path)
+            .expect("Could not create local configuration file")
+            .write_all(b"codes:\n    - GH\ninsertion: template")
+            .expect("Could not write local configuration file");
+
+        let config = Config::load_layered(crate::git::ConfigPaths {
+            global: Some(global_path),
+            local: Some(local_path),
+        })
+        .expect("Could not load layered configuration");
+
+        assert_eq!(config.insertion(), Insertion::Template);
+    }
+
+    #[test]
+    fn load_layered_global_only() {
+        let tmp_dir = TempDir::new().expect("Could not create temporary directory");
+        let global_path = tmp_dir.path().join("global.yaml");
+        fs::File::create(&global_path)
+            .expect("Could not create global configuration file")
+            .write_all(MINIMAL_CONFIGURATION.as_bytes())
+            .expect("Could not write global configuration file");
+
+        let config = Config::load_layered(crate::git::ConfigPaths {
+            global: Some(global_path),
+            local: None,
+        })
+        .expect("Could not load layered configuration");
+        assert_eq!(config.codes, Vec::from([String::from("GH")]));
+    }
+
+    #[test]
+    fn load_layered_no_paths() {
+        assert!(matches!(
+            Config::load_layered(crate::git::ConfigPaths {
+                global: None,
+                local: None
+            }),
+            Err(Error::NoConfigurationFile)
+        ));
+    }
+
     #[test]
     fn load_config_invalid() {
         let tmp_dir = TempDir::new().expect("Could not create temporary directory");
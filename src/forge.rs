@@ -0,0 +1,97 @@
+use crate::config::{Forge, ForgeKind};
+
+/// Build the issue API URL for `reference`'s numeric part on the configured
+/// forge. Only called from `fetch_title`'s `forge`-enabled branch; kept
+/// behind the same `cfg` so a default build doesn't carry dead code.
+#[cfg(feature = "forge")]
+fn issue_url(forge: &Forge, number: &str) -> String {
+    let host = forge
+        .host
+        .as_deref()
+        .unwrap_or_else(|| forge.kind.default_host());
+    match forge.kind {
+        ForgeKind::GitHub => format!("https://{}/repos/{}/issues/{}", host, forge.repo, number),
+        ForgeKind::GitLab => format!(
+            "https://{}/api/v4/projects/{}/issues/{}",
+            host,
+            forge.repo.replace('/', "%2F"),
+            number
+        ),
+    }
+}
+
+/// Fetch the title of `reference`'s issue from the configured forge,
+/// returning `None` on any network error, missing token, timeout, or
+/// unexpected response, so a lookup failure never blocks a commit. Requires
+/// the `forge` Cargo feature; compiled out (and always returns `None`)
+/// otherwise, keeping the default build network-free.
+#[cfg(feature = "forge")]
+pub fn fetch_title(forge: &Forge, reference: &str) -> Option<String> {
+    let token = std::env::var(&forge.token_env).ok()?;
+    let number = reference.rsplit('-').next()?;
+    let url = issue_url(forge, number);
+
+    let response = ureq::get(&url)
+        .set("Authorization", &format!("Bearer {}", token))
+        .set("User-Agent", "bureaucrat")
+        .timeout(std::time::Duration::from_millis(forge.timeout_ms))
+        .call()
+        .ok()?;
+    let body: serde_json::Value = response.into_json().ok()?;
+    body.get("title")?.as_str().map(str::to_string)
+}
+
+#[cfg(not(feature = "forge"))]
+pub fn fetch_title(_forge: &Forge, _reference: &str) -> Option<String> {
+    None
+}
+
+#[cfg(all(test, feature = "forge"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issue_url_github_uses_default_host() {
+        let forge = Forge {
+            kind: ForgeKind::GitHub,
+            host: None,
+            repo: String::from("arttuperala/bureaucrat"),
+            token_env: String::from("GITHUB_TOKEN"),
+            timeout_ms: 2000,
+        };
+        assert_eq!(
+            issue_url(&forge, "123"),
+            "https://api.github.com/repos/arttuperala/bureaucrat/issues/123"
+        );
+    }
+
+    #[test]
+    fn issue_url_gitlab_encodes_repo_path() {
+        let forge = Forge {
+            kind: ForgeKind::GitLab,
+            host: None,
+            repo: String::from("group/project"),
+            token_env: String::from("GITLAB_TOKEN"),
+            timeout_ms: 2000,
+        };
+        assert_eq!(
+            issue_url(&forge, "42"),
+            "https://gitlab.com/api/v4/projects/group%2Fproject/issues/42"
+        );
+    }
+
+    #[test]
+    fn issue_url_respects_custom_host() {
+        let forge = Forge {
+            kind: ForgeKind::GitHub,
+            host: Some(String::from("github.example.com")),
+            repo: String::from("group/project"),
+            token_env: String::from("GITHUB_TOKEN"),
+            timeout_ms: 2000,
+        };
+        assert_eq!(
+            issue_url(&forge, "7"),
+            "https://github.example.com/repos/group/project/issues/7"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{config, util, Error};
 use std::io::Write;
+#[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 use std::path::PathBuf;
 use std::{fs, io};
@@ -7,6 +8,98 @@ use std::{fs, io};
 static HOOK_CONTENTS: &str = "#!/usr/bin/env bash
 exec bureaucrat run \"$@\"";
 
+/// Marker embedded in chained hooks so a second `install --chain` can
+/// detect that the hook is already a bureaucrat-managed dispatcher.
+static CHAIN_MARKER: &str = "# bureaucrat:chain";
+
+/// Directory, relative to the user's config directory, holding the git
+/// template used by `install --global`.
+static GLOBAL_TEMPLATE_DIR: &str = "bureaucrat/template";
+
+fn write_hook_file(path: &PathBuf) -> Result<(), io::Error> {
+    let mut file = fs::File::create(path)?;
+    file.write_all(HOOK_CONTENTS.as_bytes())?;
+    set_executable(&file)?;
+    Ok(())
+}
+
+/// Mark a freshly written hook script executable. Git for Windows runs
+/// `prepare-commit-msg` through its bundled shell regardless of the file's
+/// Windows permission bits, so there's nothing to set there.
+#[cfg(unix)]
+fn set_executable(file: &fs::File) -> Result<(), io::Error> {
+    file.set_permissions(fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+fn set_executable(_file: &fs::File) -> Result<(), io::Error> {
+    Ok(())
+}
+
+/// Install the hook globally so every newly cloned/initialized repository
+/// picks it up automatically, following git's hook-propagation mechanism:
+/// write `hooks/prepare-commit-msg` into a template directory under the
+/// user's config dir, then point `init.templateDir` at it. With
+/// `hooks_path`, point `core.hooksPath` at the template's hooks directory
+/// instead, which also enforces the hook on existing repositories.
+///
+/// The per-invocation bare-repository guard lives in `run`, not here,
+/// since a global install has no single repository to check.
+pub fn install_global_hook(hooks_path: bool) -> Result<PathBuf, Error> {
+    let config_dir = dirs::config_dir().ok_or(Error::NoConfigDir)?;
+    let hooks_dir = config_dir.join(GLOBAL_TEMPLATE_DIR).join("hooks");
+    fs::create_dir_all(&hooks_dir).map_err(Error::Io)?;
+    let hook_path = hooks_dir.join("prepare-commit-msg");
+    write_hook_file(&hook_path).map_err(Error::Io)?;
+
+    let mut git_config = git2::Config::open_default().map_err(Error::UnknownGit)?;
+    let (key, value) = if hooks_path {
+        ("core.hooksPath", hooks_dir.to_string_lossy().into_owned())
+    } else {
+        (
+            "init.templateDir",
+            hooks_dir
+                .parent()
+                .expect("hooks dir has a parent")
+                .to_string_lossy()
+                .into_owned(),
+        )
+    };
+    if let Ok(existing) = git_config.get_string(key) {
+        if existing != value {
+            log::warn!("'{}' is already set to '{}'; leaving it unchanged", key, existing);
+            return Ok(hook_path);
+        }
+    }
+    git_config.set_str(key, &value).map_err(Error::UnknownGit)?;
+    Ok(hook_path)
+}
+
+/// Configuration file paths discovered for a repository: an optional
+/// user-level global config and an optional per-repository local config.
+/// At least one is always present.
+pub struct ConfigPaths {
+    pub global: Option<PathBuf>,
+    pub local: Option<PathBuf>,
+}
+
+/// Find the user-level global configuration file under the user's config
+/// directory, if any.
+fn discover_global_config() -> Option<PathBuf> {
+    let config_dir = dirs::config_dir()?.join("bureaucrat");
+    for filename in config::CONFIG_FILENAMES {
+        let path = config_dir.join(filename);
+        if path.exists() {
+            log::debug!(
+                "Global configuration file found at {}",
+                util::truncate_path(&path).display()
+            );
+            return Some(path);
+        }
+    }
+    None
+}
+
 pub struct Repository {
     pub repo: git2::Repository,
 }
@@ -21,12 +114,21 @@ impl Repository {
         Ok(Repository { repo })
     }
 
-    /// Find path to the Bureucrat configuration file.
-    pub fn discover_config(&self) -> Result<PathBuf, Error> {
-        let Some(root) = self.repo.workdir() else {
-            log::warn!("Could not find work directory");
+    /// Find the Bureaucrat configuration files that apply to this
+    /// repository: a user-level global config plus a per-repository local
+    /// config, either of which may be absent. A repository with no local
+    /// file can still function from global defaults, and vice versa.
+    pub fn discover_config(&self) -> Result<ConfigPaths, Error> {
+        let global = discover_global_config();
+        let local = self.discover_local_config();
+        if global.is_none() && local.is_none() {
             return Err(Error::NoConfigurationFile);
-        };
+        }
+        Ok(ConfigPaths { global, local })
+    }
+
+    fn discover_local_config(&self) -> Option<PathBuf> {
+        let root = self.repo.workdir()?;
         for filename in config::CONFIG_FILENAMES {
             let path = root.join(filename);
             if path.exists() {
@@ -34,10 +136,10 @@ impl Repository {
                     "Configuration file found at {}",
                     util::truncate_path(&path).display()
                 );
-                return Ok(path);
+                return Some(path);
             }
         }
-        Err(Error::NoConfigurationFile)
+        None
     }
 
     /// Get the name of the current HEAD branch.
@@ -54,15 +156,60 @@ impl Repository {
 
     /// Install prepare-commit-msg hook into the repository.
     pub fn install_hook(&self) -> Result<(), io::Error> {
-        let mut file = fs::File::create(self.hook_path())?;
-        file.write_all(HOOK_CONTENTS.as_bytes())?;
-        let permissions = fs::Permissions::from_mode(0o755);
-        file.set_permissions(permissions)?;
-        Ok(())
+        write_hook_file(&self.hook_path())
     }
 
+    /// Path to the prepare-commit-msg hook, honoring `core.hooksPath` when
+    /// set (resolving a relative path against the work directory) and
+    /// falling back to the default `hooks/` directory otherwise.
     pub fn hook_path(&self) -> PathBuf {
-        self.repo.path().join("hooks/prepare-commit-msg")
+        self.hooks_dir().join("prepare-commit-msg")
+    }
+
+    fn hooks_dir(&self) -> PathBuf {
+        let Ok(config) = self.repo.config() else {
+            return self.repo.path().join("hooks");
+        };
+        let Ok(hooks_path) = config.get_string("core.hooksPath") else {
+            return self.repo.path().join("hooks");
+        };
+        let hooks_path = PathBuf::from(hooks_path);
+        if hooks_path.is_relative() {
+            let root = self.repo.workdir().unwrap_or_else(|| self.repo.path());
+            root.join(hooks_path)
+        } else {
+            hooks_path
+        }
+    }
+
+    /// Chain to a pre-existing prepare-commit-msg hook instead of
+    /// overwriting it: move it aside and replace it with a dispatcher that
+    /// runs bureaucrat and then execs the saved script, propagating its
+    /// exit code. No-ops if the hook is already a bureaucrat-managed
+    /// dispatcher.
+    pub fn chain_hook(&self) -> Result<(), io::Error> {
+        let hook_path = self.hook_path();
+        let existing = fs::read_to_string(&hook_path)?;
+        if existing.contains(CHAIN_MARKER) {
+            log::debug!("Hook is already a bureaucrat-managed dispatcher");
+            return Ok(());
+        }
+
+        let local_name = format!(
+            "{}.local",
+            hook_path.file_name().and_then(|name| name.to_str()).unwrap_or("prepare-commit-msg")
+        );
+        let local_path = hook_path.with_file_name(&local_name);
+        fs::rename(&hook_path, &local_path)?;
+
+        let contents = format!(
+            "#!/usr/bin/env bash\n{}\nbureaucrat run \"$@\"\nexec \"$(dirname \"$0\")/{}\" \"$@\"",
+            CHAIN_MARKER, local_name
+        );
+        let mut file = fs::File::create(&hook_path)?;
+        file.write_all(contents.as_bytes())?;
+        set_executable(&file)?;
+        Ok(())
     }
 }
 
@@ -70,10 +217,22 @@ impl Repository {
 mod tests {
     use super::*;
     use std::io::Read;
+    use std::sync::{Mutex, MutexGuard};
     use std::{env, fs};
     use tempfile::TempDir;
     use test_case::test_case;
 
+    /// Serializes tests in this module: several of them mutate process-wide
+    /// state (`HOME`, `XDG_CONFIG_HOME`, the current directory) or read git
+    /// config that layers in whatever those currently point to, and
+    /// `cargo test`'s default parallel harness would otherwise race them
+    /// against each other.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock_env() -> MutexGuard<'static, ()> {
+        ENV_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     fn create_test_repository() -> (TempDir, Repository) {
         let tmp_dir = TempDir::new().expect("Could not create temporary directory");
         let repo = git2::Repository::init(tmp_dir.path()).expect("Could not create git repository");
@@ -81,26 +240,35 @@ mod tests {
         (tmp_dir, repository)
     }
 
+    fn isolate_global_config() -> TempDir {
+        let empty_config_dir = TempDir::new().expect("Could not create temporary directory");
+        env::set_var("XDG_CONFIG_HOME", empty_config_dir.path());
+        empty_config_dir
+    }
+
     #[test_case(".bureaucrat-config.yaml" ; "long yaml")]
     #[test_case(".bureaucrat-config.yml" ; "long yml")]
     #[test_case(".bureaucrat.yaml" ; "short yaml")]
     #[test_case(".bureaucrat.yml" ; "short yml")]
     fn discover_config(filename: &str) {
+        let _guard = lock_env();
+        let _empty_config_dir = isolate_global_config();
         let (tmp_dir, repository) = create_test_repository();
         let config_file_path = fs::canonicalize(tmp_dir.path())
             .expect("Could not get canonical path for temporary directory")
             .join(filename);
         fs::File::create(&config_file_path).expect("Could not create config file");
-        assert_eq!(
-            repository
-                .discover_config()
-                .expect("Could not discover config"),
-            config_file_path
-        );
+        let paths = repository
+            .discover_config()
+            .expect("Could not discover config");
+        assert_eq!(paths.local, Some(config_file_path));
+        assert_eq!(paths.global, None);
     }
 
     #[test]
     fn discover_config_not_found() {
+        let _guard = lock_env();
+        let _empty_config_dir = isolate_global_config();
         let (_, repository) = create_test_repository();
         assert!(matches!(
             repository.discover_config(),
@@ -108,8 +276,48 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn discover_config_global_only() {
+        let _guard = lock_env();
+        let config_dir = isolate_global_config();
+        let global_path = config_dir.path().join("bureaucrat/.bureaucrat.yaml");
+        fs::create_dir_all(global_path.parent().expect("global path has a parent"))
+            .expect("Could not create global config directory");
+        fs::File::create(&global_path).expect("Could not create global config file");
+
+        let (_, repository) = create_test_repository();
+        let paths = repository
+            .discover_config()
+            .expect("Could not discover config");
+        assert_eq!(paths.global, Some(global_path));
+        assert_eq!(paths.local, None);
+    }
+
+    #[test]
+    fn discover_config_global_and_local() {
+        let _guard = lock_env();
+        let config_dir = isolate_global_config();
+        let global_path = config_dir.path().join("bureaucrat/.bureaucrat.yaml");
+        fs::create_dir_all(global_path.parent().expect("global path has a parent"))
+            .expect("Could not create global config directory");
+        fs::File::create(&global_path).expect("Could not create global config file");
+
+        let (tmp_dir, repository) = create_test_repository();
+        let local_path = fs::canonicalize(tmp_dir.path())
+            .expect("Could not get canonical path for temporary directory")
+            .join(".bureaucrat.yaml");
+        fs::File::create(&local_path).expect("Could not create local config file");
+
+        let paths = repository
+            .discover_config()
+            .expect("Could not discover config");
+        assert_eq!(paths.global, Some(global_path));
+        assert_eq!(paths.local, Some(local_path));
+    }
+
     #[test]
     fn get_current_branch() {
+        let _guard = lock_env();
         let (tmp_dir, repository) = create_test_repository();
         env::set_current_dir(tmp_dir.path()).expect("Could not set current directory");
         let signature = git2::Signature::now("John Developer", "john@example.com")
@@ -150,6 +358,7 @@ mod tests {
 
     #[test]
     fn get_current_branch_unborn() {
+        let _guard = lock_env();
         let (tmp_dir, repository) = create_test_repository();
         env::set_current_dir(tmp_dir.path()).expect("Could not set current directory");
         assert!(matches!(repository.current_branch(), Err(Error::NoBranch)));
@@ -157,6 +366,7 @@ mod tests {
 
     #[test]
     fn install() {
+        let _guard = lock_env();
         let (tmp_dir, repository) = create_test_repository();
         repository.install_hook().expect("Could not install hook");
         let expected_hook_path = fs::canonicalize(tmp_dir.path())
@@ -169,8 +379,80 @@ mod tests {
         assert_eq!(file_content, HOOK_CONTENTS);
     }
 
+    #[test]
+    fn chain_existing_hook() {
+        let _guard = lock_env();
+        let (tmp_dir, repository) = create_test_repository();
+        let hook_path = repository.hook_path();
+        fs::write(&hook_path, "#!/bin/sh\necho legacy").expect("Could not create hook file");
+
+        repository.chain_hook().expect("Could not chain hook");
+
+        let dispatcher = fs::read_to_string(&hook_path).expect("Could not read dispatcher hook");
+        assert!(dispatcher.contains(CHAIN_MARKER));
+        assert!(dispatcher.contains("bureaucrat run"));
+        assert!(dispatcher.contains("prepare-commit-msg.local"));
+
+        let local_path = tmp_dir.path().join(".git/hooks/prepare-commit-msg.local");
+        let local_contents = fs::read_to_string(local_path).expect("Could not read saved hook");
+        assert_eq!(local_contents, "#!/bin/sh\necho legacy");
+    }
+
+    #[test]
+    fn chain_is_idempotent() {
+        let _guard = lock_env();
+        let (_, repository) = create_test_repository();
+        let hook_path = repository.hook_path();
+        fs::write(&hook_path, "#!/bin/sh\necho legacy").expect("Could not create hook file");
+
+        repository.chain_hook().expect("Could not chain hook");
+        let first_pass = fs::read_to_string(&hook_path).expect("Could not read dispatcher hook");
+
+        repository.chain_hook().expect("Could not re-chain hook");
+        let second_pass = fs::read_to_string(&hook_path).expect("Could not read dispatcher hook");
+
+        assert_eq!(first_pass, second_pass);
+    }
+
+    #[test]
+    fn global_install() {
+        let _guard = lock_env();
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
+        env::set_var("HOME", home_dir.path());
+        env::remove_var("XDG_CONFIG_HOME");
+
+        let hook_path = install_global_hook(false).expect("Could not install global hook");
+        assert!(hook_path.exists());
+
+        let global_config = fs::read_to_string(home_dir.path().join(".gitconfig"))
+            .expect("Could not read .gitconfig");
+        assert!(global_config.contains("templateDir"));
+    }
+
+    #[test]
+    fn global_install_hooks_path() {
+        let _guard = lock_env();
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
+        env::set_var("HOME", home_dir.path());
+        env::remove_var("XDG_CONFIG_HOME");
+
+        let hook_path = install_global_hook(true).expect("Could not install global hook");
+        assert!(hook_path.exists());
+
+        let git_config = git2::Config::open_default().expect("Could not open default git config");
+        let hooks_path = git_config
+            .get_string("core.hooksPath")
+            .expect("core.hooksPath was not set");
+        assert_eq!(
+            PathBuf::from(hooks_path),
+            hook_path.parent().expect("hook path has a parent")
+        );
+        assert!(git_config.get_string("init.templateDir").is_err());
+    }
+
     #[test]
     fn hook_path() {
+        let _guard = lock_env();
         let (tmp_dir, repository) = create_test_repository();
         let expected_hook_path = fs::canonicalize(tmp_dir.path())
             .expect("Could not get canonical path for temporary directory")
@@ -178,8 +460,42 @@ mod tests {
         assert_eq!(repository.hook_path(), expected_hook_path);
     }
 
+    #[test]
+    fn hook_path_respects_relative_hooks_path() {
+        let _guard = lock_env();
+        let (tmp_dir, repository) = create_test_repository();
+        repository
+            .repo
+            .config()
+            .expect("Could not open repository config")
+            .set_str("core.hooksPath", ".husky")
+            .expect("Could not set core.hooksPath");
+        let expected_hook_path = fs::canonicalize(tmp_dir.path())
+            .expect("Could not get canonical path for temporary directory")
+            .join(".husky/prepare-commit-msg");
+        assert_eq!(repository.hook_path(), expected_hook_path);
+    }
+
+    #[test]
+    fn hook_path_respects_absolute_hooks_path() {
+        let _guard = lock_env();
+        let (_, repository) = create_test_repository();
+        let hooks_dir = TempDir::new().expect("Could not create temporary directory");
+        repository
+            .repo
+            .config()
+            .expect("Could not open repository config")
+            .set_str("core.hooksPath", &hooks_dir.path().to_string_lossy())
+            .expect("Could not set core.hooksPath");
+        assert_eq!(
+            repository.hook_path(),
+            hooks_dir.path().join("prepare-commit-msg")
+        );
+    }
+
     #[test]
     fn open() {
+        let _guard = lock_env();
         let (tmp_dir, _) = create_test_repository();
         env::set_current_dir(tmp_dir.path()).expect("Could not set current directory");
         let repository = Repository::open();
@@ -188,6 +504,7 @@ mod tests {
 
     #[test]
     fn open_from_subdirectory() {
+        let _guard = lock_env();
         let (tmp_dir, _) = create_test_repository();
         let subdirectory = tmp_dir.path().join("src/module/submodule");
         fs::create_dir_all(&subdirectory).expect("Could not create directories");
@@ -198,6 +515,7 @@ mod tests {
 
     #[test]
     fn open_without_repository() {
+        let _guard = lock_env();
         let tmp_dir = TempDir::new().expect("Could not create temporary directory");
         env::set_current_dir(tmp_dir.path()).expect("Could not set current directory");
         let repository = Repository::open();
@@ -7,8 +7,11 @@ extern crate pretty_env_logger;
 use std::process::ExitCode;
 
 mod config;
+mod forge;
 mod git;
 mod parse;
+mod template;
+mod trailer;
 mod util;
 
 #[derive(Debug)]
@@ -17,9 +20,13 @@ enum Error {
     FileNotFound(PathBuf),
     /// Configuration file could not be parsed.
     InvalidConfiguration(serde_yaml::Error),
+    /// TOML configuration file could not be parsed.
+    InvalidTomlConfiguration(toml::de::Error),
     Io(io::Error),
     /// No git branch could be found.
     NoBranch,
+    /// Could not determine the user's config directory.
+    NoConfigDir,
     /// No bureucrat configuration could be found.
     NoConfigurationFile,
     /// No git repository could be found.
@@ -44,16 +51,41 @@ enum Commands {
     /// Install the prepare-commit-msg hook.
     Install(InstallArgs),
 
+    /// Write a default configuration file.
+    Init(InitArgs),
+
     /// Entrypoint for the prepare-commit-msg hook.
     #[command(hide = true)]
     Run(RunArgs),
 }
 
+#[derive(Args, Debug)]
+struct InitArgs {
+    /// Write the configuration file even if one exists already.
+    #[arg(long)]
+    overwrite: bool,
+}
+
 #[derive(Args, Debug)]
 struct InstallArgs {
     /// Install hook even if a prepare-commit-msg exists already.
-    #[arg(long)]
+    #[arg(long, conflicts_with = "chain")]
     overwrite: bool,
+
+    /// Preserve an existing prepare-commit-msg hook by chaining to it
+    /// instead of overwriting it.
+    #[arg(long)]
+    chain: bool,
+
+    /// Install the hook globally, for every repository, instead of just
+    /// the current one.
+    #[arg(long)]
+    global: bool,
+
+    /// With --global, enforce the hook on existing repositories via
+    /// `core.hooksPath` instead of `init.templateDir`.
+    #[arg(long, requires = "global")]
+    hooks_path: bool,
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -90,7 +122,48 @@ struct RunArgs {
     hash: Option<String>,
 }
 
+static DEFAULT_CONFIG: &str = "# Issue codes recognized in branch names, e.g. GH, JIRA.
+codes:
+  - GH
+
+# Branch prefixes that must precede a code, e.g. feature, bugfix.
+# Leave empty to accept branches with any prefix, or none.
+branch_prefixes: []
+";
+
+fn init(args: &InitArgs) -> Result<(), Error> {
+    let repository = git::Repository::open()?;
+    let Some(root) = repository.repo.workdir() else {
+        log::warn!("Could not find work directory");
+        return Err(Error::Exit(ExitCode::from(1)));
+    };
+    let config_path = root.join(".bureaucrat.yaml");
+    if config_path.exists() && !args.overwrite {
+        log::error!(
+            "Configuration already exists at {}",
+            util::truncate_path(&config_path).display()
+        );
+        log::info!("Use `bureaucrat init --overwrite` to write configuration anyways");
+        return Err(Error::Exit(ExitCode::from(1)));
+    }
+    fs::write(&config_path, DEFAULT_CONFIG).map_err(Error::Io)?;
+    log::info!(
+        "Configuration written to {}",
+        util::truncate_path(&config_path).display()
+    );
+    Ok(())
+}
+
 fn install(args: &InstallArgs) -> Result<(), Error> {
+    if args.global {
+        let hook_path = git::install_global_hook(args.hooks_path)?;
+        log::info!(
+            "Hook installed globally at {}",
+            util::truncate_path(&hook_path).display()
+        );
+        return Ok(());
+    }
+
     let repository = git::Repository::open()?;
     if repository.repo.is_bare() {
         log::warn!("Repository is bare; not installing hook");
@@ -98,6 +171,14 @@ fn install(args: &InstallArgs) -> Result<(), Error> {
     }
     let hook_path = repository.hook_path();
     if hook_path.exists() {
+        if args.chain {
+            repository.chain_hook().map_err(Error::Io)?;
+            log::info!(
+                "Hook chained at {}",
+                util::truncate_path(&hook_path).display()
+            );
+            return Ok(());
+        }
         if !args.overwrite {
             log::error!(
                 "Hook already exists at {}",
@@ -127,8 +208,12 @@ fn run(args: &RunArgs) -> Result<(), Error> {
     }
 
     let repository = git::Repository::open()?;
-    let config_file_path = repository.discover_config()?;
-    let config = config::Config::load(config_file_path)?;
+    if repository.repo.is_bare() {
+        log::warn!("Repository is bare; not tagging commit");
+        return Err(Error::Exit(ExitCode::from(1)));
+    }
+    let config_paths = repository.discover_config()?;
+    let config = config::Config::load_layered(config_paths)?;
     log::debug!(
         "Using codes {:?} for branches {:?}",
         config.codes,
@@ -145,8 +230,35 @@ fn run(args: &RunArgs) -> Result<(), Error> {
         io::ErrorKind::NotFound => Error::FileNotFound(args.path.clone()),
         _ => Error::Io(error),
     })?;
+    let rendered = match config.insertion() {
+        config::Insertion::Template => {
+            // The forge title is free-form prose, so it only goes into the
+            // `issue` template variable, never into the structured trailer
+            // value below: that must stay the bare reference, or a title
+            // that changes between invocations (rename, flaky fetch,
+            // --amend) would defeat `trailer::insert`'s idempotency check.
+            let title = config
+                .forge
+                .as_ref()
+                .and_then(|forge| forge::fetch_title(forge, &reference));
+            let issue = match &title {
+                Some(title) => format!("{} {}", reference, title),
+                None => reference.clone(),
+            };
+            let (code, number) = reference.split_once('-').unwrap_or((reference.as_str(), ""));
+            let vars = std::collections::HashMap::from([
+                ("issue", issue.as_str()),
+                ("branch", branch.as_str()),
+                ("message", contents.as_str()),
+                ("code", code),
+                ("number", number),
+            ]);
+            template::render(config.template(), &vars)
+        }
+        config::Insertion::Trailer => trailer::insert(&contents, config.trailer_key(), &reference),
+    };
     let mut temp_file = NamedTempFile::new().map_err(Error::Io)?;
-    write!(temp_file, "\n\n{}{}", reference, contents).map_err(Error::Io)?;
+    write!(temp_file, "{}", rendered).map_err(Error::Io)?;
     if let Err(error) = temp_file.persist(&args.path) {
         log::warn!("Could not ovewrite commit message: {}", error);
     };
@@ -162,6 +274,7 @@ fn main() -> ExitCode {
 
     let result = match &cli.command {
         Commands::Install(args) => install(args),
+        Commands::Init(args) => init(args),
         Commands::Run(args) => run(args),
     };
     match result {
@@ -175,6 +288,10 @@ fn main() -> ExitCode {
             log::warn!("Configuration could not be parsed: {}", error);
             ExitCode::SUCCESS
         }
+        Err(Error::InvalidTomlConfiguration(error)) => {
+            log::warn!("Configuration could not be parsed: {}", error);
+            ExitCode::SUCCESS
+        }
         Err(Error::Io(error)) => {
             log::error!("IO error: {}", error);
             ExitCode::from(1)
@@ -183,6 +300,10 @@ fn main() -> ExitCode {
             log::warn!("Branch doesn't exist yet");
             ExitCode::SUCCESS
         }
+        Err(Error::NoConfigDir) => {
+            log::error!("Could not determine the user's config directory");
+            ExitCode::from(1)
+        }
         Err(Error::NoConfigurationFile) => {
             log::warn!("No configuration file was found");
             ExitCode::SUCCESS
@@ -1,4 +1,5 @@
 use crate::config;
+use regex::Regex;
 
 fn extract_number_sequences(value: &str, amount: usize) -> &str {
     let mut start: Option<usize> = None;
@@ -45,6 +46,9 @@ pub fn find_issue_reference(config: &config::Config, branch: &str) -> Option<Str
     if !prefix_matches(branch, &config.branch_prefixes) {
         return None;
     }
+    if !config.patterns.is_empty() {
+        return find_pattern_reference(&config.patterns, branch);
+    }
     let mut index: Option<usize> = Some(0);
     while let Some(i) = index {
         for code in &config.codes {
@@ -70,6 +74,51 @@ pub fn find_issue_reference(config: &config::Config, branch: &str) -> Option<Str
     None
 }
 
+/// Try each configured regex pattern, anchored at the start of the
+/// remaining branch segment, and render the first match's `format`
+/// against its named captures.
+fn find_pattern_reference(patterns: &[config::Pattern], branch: &str) -> Option<String> {
+    let mut index: Option<usize> = Some(0);
+    while let Some(i) = index {
+        let remaining = &branch[i..];
+        for pattern in patterns {
+            let anchored = format!("^(?:{})", pattern.pattern);
+            let Ok(regex) = Regex::new(&anchored) else {
+                continue;
+            };
+            if let Some(captures) = regex.captures(remaining) {
+                return Some(render_pattern(&pattern.format, &captures));
+            }
+        }
+        index = branch[i..].find('/').map(|x| x + 1);
+    }
+    None
+}
+
+/// Render a pattern's `format` string, substituting `{name}` with the
+/// value of the named capture group `name`.
+fn render_pattern(format: &str, captures: &regex::Captures) -> String {
+    let mut output = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '{' {
+            output.push(c);
+            continue;
+        }
+        let mut name = String::new();
+        for c in chars.by_ref() {
+            if c == '}' {
+                break;
+            }
+            name.push(c);
+        }
+        if let Some(value) = captures.name(&name) {
+            output.push_str(value.as_str());
+        }
+    }
+    output
+}
+
 fn prefix_matches(branch: &str, prefixes: &Vec<String>) -> bool {
     if prefixes.is_empty() {
         return true;
@@ -129,6 +178,7 @@ mod tests {
         let config = Config {
             codes: vec!["GH".to_string()],
             branch_prefixes: vec![],
+            ..Config::default()
         };
         let reference = find_issue_reference(&config, branch);
         assert_eq!(reference, expected.map(|s| s.to_string()));
@@ -153,6 +203,7 @@ mod tests {
         let config = Config {
             codes: vec!["GH".to_string(), "GG".to_string()],
             branch_prefixes: vec![],
+            ..Config::default()
         };
         let reference = find_issue_reference(&config, branch);
         assert_eq!(reference, expected.map(|s| s.to_string()));
@@ -170,6 +221,7 @@ mod tests {
         let config = Config {
             codes: vec!["TEAM1".to_string()],
             branch_prefixes: vec![],
+            ..Config::default()
         };
         let reference = find_issue_reference(&config, branch);
         assert_eq!(reference, expected.map(|s| s.to_string()));
@@ -191,6 +243,7 @@ mod tests {
         let config = Config {
             codes: vec!["TEAM1".to_string(), "TEAM2".to_string()],
             branch_prefixes: vec![],
+            ..Config::default()
         };
         let reference = find_issue_reference(&config, branch);
         assert_eq!(reference, expected.map(|s| s.to_string()));
@@ -215,6 +268,24 @@ mod tests {
         let config = Config {
             codes: vec!["GIT".to_string()],
             branch_prefixes: vec!["feature".to_string(), "security".to_string()],
+            ..Config::default()
+        };
+        let reference = find_issue_reference(&config, branch);
+        assert_eq!(reference, expected.map(|s| s.to_string()));
+    }
+
+    #[test_case("AB12CD-345", Some("AB12CD-345") ; "letters and digits with dash")]
+    #[test_case("AB12CD345", Some("AB12CD-345") ; "letters and digits without dash")]
+    #[test_case("feature/AB12CD-345-my-issue", Some("AB12CD-345") ; "prefixed with suffix")]
+    #[test_case("master", None ; "no match")]
+    fn find_issue_reference_patterns(branch: &str, expected: Option<&str>) {
+        let config = Config {
+            codes: vec!["GH".to_string()],
+            patterns: vec![config::Pattern {
+                pattern: String::from(r"(?P<code>[A-Z]+[0-9]*[A-Z]*)-?(?P<num>\d+)"),
+                format: String::from("{code}-{num}"),
+            }],
+            ..Config::default()
         };
         let reference = find_issue_reference(&config, branch);
         assert_eq!(reference, expected.map(|s| s.to_string()));
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+/// Render a commit-message template against a small set of variables:
+/// `issue` (the full matched reference), `code` and `number` (its parsed
+/// components, split on the first `-`), `branch`, and `message` (the
+/// pre-existing commit body).
+///
+/// Literal text is copied verbatim. `{name}` is replaced with the value of
+/// `name` looked up in `vars`, left untouched if `name` is unknown. A
+/// conditional group `{name?:...}` renders its inner template only when
+/// `name` is present and non-empty. `\{` escapes a literal `{`.
+pub fn render(template: &str, vars: &HashMap<&str, &str>) -> String {
+    let mut output = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'{') => {
+                output.push('{');
+                chars.next();
+            }
+            '{' => output.push_str(&render_group(&mut chars, vars)),
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+/// Consume characters up to the brace matching the one already read, then
+/// render either a plain substitution or a `name?:...` conditional group.
+fn render_group(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+    vars: &HashMap<&str, &str>,
+) -> String {
+    let mut token = String::new();
+    let mut depth = 1;
+    for c in chars.by_ref() {
+        match c {
+            '{' => {
+                depth += 1;
+                token.push(c);
+            }
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+                token.push(c);
+            }
+            c => token.push(c),
+        }
+    }
+
+    if let Some((name, inner)) = token.split_once("?:") {
+        match vars.get(name) {
+            Some(value) if !value.is_empty() => render(inner, vars),
+            _ => String::new(),
+        }
+    } else {
+        match vars.get(token.as_str()) {
+            Some(value) => value.to_string(),
+            None => format!("{{{}}}", token),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("{issue}", "GH-123" ; "single placeholder")]
+    #[test_case("{issue}: {message}", "GH-123: fix bug" ; "multiple placeholders")]
+    #[test_case("[{issue}] {message}", "[GH-123] fix bug" ; "bracketed placeholder")]
+    #[test_case("no placeholders here", "no placeholders here" ; "no placeholders")]
+    #[test_case("{unknown}", "{unknown}" ; "unknown placeholder left literal")]
+    #[test_case("\\{issue}", "{issue}" ; "escaped brace")]
+    fn render_basic(template: &str, expected: &str) {
+        let vars = HashMap::from([("issue", "GH-123"), ("message", "fix bug")]);
+        assert_eq!(render(template, &vars), expected);
+    }
+
+    #[test]
+    fn render_conditional_present() {
+        let vars = HashMap::from([("issue", "GH-123"), ("message", "fix bug")]);
+        assert_eq!(
+            render("{issue?:\n\n{issue}}{message}", &vars),
+            "\n\nGH-123fix bug"
+        );
+    }
+
+    #[test]
+    fn render_conditional_absent() {
+        let vars = HashMap::from([("issue", ""), ("message", "fix bug")]);
+        assert_eq!(render("{issue?:\n\n{issue}}{message}", &vars), "fix bug");
+    }
+
+    #[test]
+    fn render_conditional_missing_variable() {
+        let vars = HashMap::from([("message", "fix bug")]);
+        assert_eq!(render("{issue?:\n\n{issue}}{message}", &vars), "fix bug");
+    }
+}
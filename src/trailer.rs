@@ -0,0 +1,119 @@
+/// Insert a Git trailer line into a commit message body, honoring the same
+/// trailer-block rules `git interpret-trailers` uses: a trailing paragraph
+/// where every non-blank line looks like `Key: value` is treated as an
+/// existing trailer block and the new line is appended to it; otherwise a
+/// blank separator line is inserted before the trailer. A comment/scissors
+/// block at the bottom of the file (lines starting with `#`) is preserved
+/// verbatim. Insertion is idempotent: a trailer with the same key and value
+/// already present is left untouched.
+pub fn insert(contents: &str, key: &str, value: &str) -> String {
+    let (body, comment_block) = split_comment_block(contents);
+    let trailer_line = format!("{}: {}", key, value);
+    let body = body.trim_end_matches('\n');
+
+    if body.lines().any(|line| line == trailer_line) {
+        return contents.to_string();
+    }
+
+    let mut result = String::from(body);
+    if body.is_empty() {
+        result.push_str(&trailer_line);
+    } else if is_trailer_block(last_paragraph(body)) {
+        result.push('\n');
+        result.push_str(&trailer_line);
+    } else {
+        result.push_str("\n\n");
+        result.push_str(&trailer_line);
+    }
+    result.push('\n');
+    result.push_str(comment_block);
+    result
+}
+
+/// Split `contents` at the first line that starts a `#`-comment block,
+/// returning `(body, comment_block)`. The comment block, if any, keeps its
+/// leading newline so it can be appended back verbatim.
+fn split_comment_block(contents: &str) -> (&str, &str) {
+    match contents.find("\n#") {
+        Some(index) => (&contents[..index], &contents[index..]),
+        None if contents.starts_with('#') => ("", contents),
+        None => (contents, ""),
+    }
+}
+
+fn last_paragraph(body: &str) -> &str {
+    match body.rfind("\n\n") {
+        Some(index) => &body[index + 2..],
+        None => body,
+    }
+}
+
+fn is_trailer_block(paragraph: &str) -> bool {
+    if paragraph.trim().is_empty() {
+        return false;
+    }
+    paragraph
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .all(is_trailer_line)
+}
+
+fn is_trailer_line(line: &str) -> bool {
+    match line.split_once(": ") {
+        Some((key, _)) => !key.is_empty() && key.chars().all(|c| c.is_ascii_alphabetic() || c == '-'),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static GIT_COMMIT_MSG: &str = "\n# Please enter the commit message for your changes. \
+        Lines starting\n# with '#' will be ignored, and an empty message aborts the commit.\n";
+
+    #[test]
+    fn insert_into_empty_body() {
+        let output = insert(GIT_COMMIT_MSG, "Issue", "GH-123");
+        assert_eq!(output, format!("Issue: GH-123\n{}", GIT_COMMIT_MSG));
+    }
+
+    #[test]
+    fn insert_after_plain_paragraph() {
+        let contents = format!("Fix the bug\n{}", GIT_COMMIT_MSG);
+        let output = insert(&contents, "Issue", "GH-123");
+        assert_eq!(
+            output,
+            format!("Fix the bug\n\nIssue: GH-123\n{}", GIT_COMMIT_MSG)
+        );
+    }
+
+    #[test]
+    fn insert_into_existing_trailer_block() {
+        let contents = format!(
+            "Fix the bug\n\nSigned-off-by: John Developer\n{}",
+            GIT_COMMIT_MSG
+        );
+        let output = insert(&contents, "Issue", "GH-123");
+        assert_eq!(
+            output,
+            format!(
+                "Fix the bug\n\nSigned-off-by: John Developer\nIssue: GH-123\n{}",
+                GIT_COMMIT_MSG
+            )
+        );
+    }
+
+    #[test]
+    fn insert_is_idempotent() {
+        let contents = format!("Fix the bug\n\nIssue: GH-123\n{}", GIT_COMMIT_MSG);
+        let output = insert(&contents, "Issue", "GH-123");
+        assert_eq!(output, contents);
+    }
+
+    #[test]
+    fn insert_without_comment_block() {
+        let output = insert("Fix the bug", "Issue", "GH-123");
+        assert_eq!(output, "Fix the bug\n\nIssue: GH-123\n");
+    }
+}
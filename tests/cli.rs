@@ -68,6 +68,123 @@ mod install {
         let message = predicate::str::contains("Hook installed at .git/hooks/prepare-commit-msg");
         cmd.assert().success().stderr(message);
     }
+
+    #[test]
+    fn chain_existing() {
+        let (tmp_dir, _) = create_repository();
+        let hook_path = tmp_dir.path().join(".git/hooks/prepare-commit-msg");
+        fs::write(&hook_path, "#!/bin/sh\necho legacy").expect("Could not create hook file");
+
+        let mut cmd = binary();
+        cmd.current_dir(tmp_dir.path())
+            .arg("install")
+            .arg("--chain");
+        let message = predicate::str::contains("Hook chained at .git/hooks/prepare-commit-msg");
+        cmd.assert().success().stderr(message);
+
+        let local_path = tmp_dir.path().join(".git/hooks/prepare-commit-msg.local");
+        let local_contents = fs::read_to_string(local_path).expect("Could not read saved hook");
+        assert_eq!(local_contents, "#!/bin/sh\necho legacy");
+    }
+
+    #[test]
+    fn global() {
+        let (repo_dir, _) = create_repository();
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
+
+        let mut cmd = binary();
+        cmd.current_dir(repo_dir.path())
+            .env("HOME", home_dir.path())
+            .env_remove("XDG_CONFIG_HOME")
+            .arg("install")
+            .arg("--global");
+        let message = predicate::str::contains("Hook installed globally at");
+        cmd.assert().success().stderr(message);
+
+        let hook_path = home_dir
+            .path()
+            .join(".config/bureaucrat/template/hooks/prepare-commit-msg");
+        assert!(hook_path.exists());
+
+        let global_config =
+            fs::read_to_string(home_dir.path().join(".gitconfig")).expect("Could not read .gitconfig");
+        assert!(global_config.contains("templateDir"));
+    }
+
+    #[test]
+    fn global_hooks_path() {
+        let (repo_dir, _) = create_repository();
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
+
+        let mut cmd = binary();
+        cmd.current_dir(repo_dir.path())
+            .env("HOME", home_dir.path())
+            .env_remove("XDG_CONFIG_HOME")
+            .arg("install")
+            .arg("--global")
+            .arg("--hooks-path");
+        let message = predicate::str::contains("Hook installed globally at");
+        cmd.assert().success().stderr(message);
+
+        let hook_path = home_dir
+            .path()
+            .join(".config/bureaucrat/template/hooks/prepare-commit-msg");
+        assert!(hook_path.exists());
+
+        let global_config =
+            fs::read_to_string(home_dir.path().join(".gitconfig")).expect("Could not read .gitconfig");
+        assert!(global_config.contains("hooksPath"));
+        assert!(!global_config.contains("templateDir"));
+    }
+}
+
+mod init {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let (tmp_dir, _) = create_repository();
+        let mut cmd = binary();
+        cmd.current_dir(tmp_dir.path()).arg("init");
+        let message = predicate::str::contains("Configuration written to .bureaucrat.yaml");
+        cmd.assert().success().stderr(message);
+
+        let config_path = tmp_dir.path().join(".bureaucrat.yaml");
+        assert!(config_path.exists());
+    }
+
+    #[test]
+    fn existing() {
+        let (tmp_dir, _) = create_repository();
+        let config_path = tmp_dir.path().join(".bureaucrat.yaml");
+        fs::write(&config_path, "codes:\n  - GH").expect("Could not create configuration file");
+
+        let mut cmd = binary();
+        cmd.current_dir(tmp_dir.path()).arg("init");
+        let message =
+            predicate::str::contains("Configuration already exists at .bureaucrat.yaml");
+        cmd.assert().failure().stderr(message);
+
+        let contents = fs::read_to_string(&config_path).expect("Could not read configuration file");
+        assert_eq!(contents, "codes:\n  - GH");
+    }
+
+    #[test]
+    fn overwrite_existing() {
+        let (tmp_dir, _) = create_repository();
+        let config_path = tmp_dir.path().join(".bureaucrat.yaml");
+        fs::write(&config_path, "codes:\n  - GH").expect("Could not create configuration file");
+
+        let mut cmd = binary();
+        cmd.current_dir(tmp_dir.path())
+            .arg("init")
+            .arg("--overwrite");
+        let message = predicate::str::contains("Configuration written to .bureaucrat.yaml");
+        cmd.assert().success().stderr(message);
+
+        let contents = fs::read_to_string(&config_path).expect("Could not read configuration file");
+        assert_ne!(contents, "codes:\n  - GH");
+    }
 }
 
 mod run {
@@ -132,9 +249,12 @@ mod run {
         init_repository(repository, "feature/GH-123-test");
         write_configuration(tmp_dir.path());
         let commit_file = create_commit_file();
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
 
         let mut cmd = binary();
         cmd.current_dir(tmp_dir.path())
+            .env("HOME", home_dir.path())
+            .env_remove("XDG_CONFIG_HOME")
             .arg("run")
             .arg(commit_file.path());
         cmd.assert().success().stderr("");
@@ -148,9 +268,12 @@ mod run {
         let (tmp_dir, _) = create_repository();
         write_configuration(tmp_dir.path());
         let commit_file = create_commit_file();
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
 
         let mut cmd = binary();
         cmd.current_dir(tmp_dir.path())
+            .env("HOME", home_dir.path())
+            .env_remove("XDG_CONFIG_HOME")
             .arg("run")
             .arg(commit_file.path());
         let message = predicate::str::contains("Branch doesn't exist yet");
@@ -165,9 +288,15 @@ mod run {
         let (tmp_dir, repository) = create_repository();
         init_repository(repository, "feature/GH-123-test");
         let commit_file = create_commit_file();
+        // No global bureaucrat config must exist either, or discover_config()
+        // would pick it up and this test would pass or fail depending on the
+        // machine running the suite.
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
 
         let mut cmd = binary();
         cmd.current_dir(tmp_dir.path())
+            .env("HOME", home_dir.path())
+            .env_remove("XDG_CONFIG_HOME")
             .arg("run")
             .arg(commit_file.path());
         let message = predicate::str::contains("No configuration file was found");
@@ -181,9 +310,12 @@ mod run {
     fn no_repository() {
         let tmp_dir = TempDir::new().expect("Could not create temporary directory");
         let commit_file = create_commit_file();
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
 
         let mut cmd = binary();
         cmd.current_dir(tmp_dir.path())
+            .env("HOME", home_dir.path())
+            .env_remove("XDG_CONFIG_HOME")
             .arg("run")
             .arg(commit_file.path());
         let message = predicate::str::contains("Could not find repository");
@@ -199,9 +331,12 @@ mod run {
         init_repository(repository, "feature/GH-123-test");
         write_configuration(tmp_dir.path());
         let commit_file = create_commit_file();
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
 
         let mut cmd = binary();
         cmd.current_dir(tmp_dir.path())
+            .env("HOME", home_dir.path())
+            .env_remove("XDG_CONFIG_HOME")
             .arg("run")
             .arg(commit_file.path())
             .arg("template");
@@ -211,6 +346,32 @@ mod run {
         assert_eq!(file_content, format!("\n\nGH-123{}", GIT_COMMIT_MSG));
     }
 
+    #[test]
+    fn custom_template_with_code_and_number() {
+        let (tmp_dir, repository) = create_repository();
+        init_repository(repository, "feature/GH-123-test");
+        let config_path = tmp_dir.path().join(".bureaucrat-config.yaml");
+        let mut config_file = fs::File::create(config_path).expect("Could not create configuration file");
+        write!(config_file, "codes:\n- GH\ntemplate: \"[{{code}} #{{number}}] {{message}}\"")
+            .expect("Couldn't write configuration");
+        let commit_file = create_commit_file();
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
+
+        let mut cmd = binary();
+        cmd.current_dir(tmp_dir.path())
+            .env("HOME", home_dir.path())
+            .env_remove("XDG_CONFIG_HOME")
+            .arg("run")
+            .arg(commit_file.path());
+        cmd.assert().success().stderr("");
+
+        let file_content = read_commit_file(commit_file);
+        assert_eq!(
+            file_content,
+            format!("[GH #123] {}", GIT_COMMIT_MSG)
+        );
+    }
+
     #[test_case("commit" ; "commit")]
     #[test_case("merge" ; "merge")]
     #[test_case("message" ; "message")]
@@ -219,9 +380,12 @@ mod run {
         let (tmp_dir, repository) = create_repository();
         init_repository(repository, "feature/GH-123-test");
         let commit_file = create_commit_file();
+        let home_dir = TempDir::new().expect("Could not create temporary directory");
 
         let mut cmd = binary();
         cmd.current_dir(tmp_dir.path())
+            .env("HOME", home_dir.path())
+            .env_remove("XDG_CONFIG_HOME")
             .arg("run")
             .arg(commit_file.path())
             .arg(commit_source);